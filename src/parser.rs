@@ -1,4 +1,6 @@
-use chrono::Weekday;
+use std::collections::VecDeque;
+
+use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime, Weekday};
 use pest::iterators::{Pair, Pairs};
 use pest::Parser;
 use thiserror::Error;
@@ -26,17 +28,27 @@ pub enum ParseError {
     UnknownModifier(String),
     #[error("unknown quantifier `{0}`")]
     UnknownQuantifier(String),
+    #[error("unknown frequency `{0}`")]
+    UnknownFreq(String),
+    #[error("recurrence interval must be greater than zero")]
+    ZeroInterval,
+    #[error("unknown direction `{0}`")]
+    UnknownDirection(String),
+    #[error("unknown month `{0}`")]
+    UnknownMonth(String),
+    #[error("offset nesting exceeds maximum depth of {0}")]
+    OffsetTooDeep(usize),
 }
 
 fn weekday_from(s: &str) -> Result<Weekday, ParseError> {
-    match s {
-        "monday" => Ok(Weekday::Mon),
-        "tuesday" => Ok(Weekday::Tue),
-        "wednesday" => Ok(Weekday::Wed),
-        "thursday" => Ok(Weekday::Thu),
-        "friday" => Ok(Weekday::Fri),
-        "saturday" => Ok(Weekday::Sat),
-        "sunday" => Ok(Weekday::Sun),
+    match s.to_lowercase().as_str() {
+        "monday" | "mon" => Ok(Weekday::Mon),
+        "tuesday" | "tue" => Ok(Weekday::Tue),
+        "wednesday" | "wed" => Ok(Weekday::Wed),
+        "thursday" | "thu" => Ok(Weekday::Thu),
+        "friday" | "fri" => Ok(Weekday::Fri),
+        "saturday" | "sat" => Ok(Weekday::Sat),
+        "sunday" | "sun" => Ok(Weekday::Sun),
         _ => Err(ParseError::UnknownWeekday(s.to_string())),
     }
 }
@@ -48,13 +60,31 @@ pub enum ShortcutDay {
 }
 
 fn shortcut_day_from(s: &str) -> Result<ShortcutDay, ParseError> {
-    match s {
+    match s.to_lowercase().as_str() {
         "today" => Ok(ShortcutDay::Today),
         "yesterday" => Ok(ShortcutDay::Yesterday),
         _ => Err(ParseError::UnknownShortcutDay(s.to_string())),
     }
 }
 
+fn month_from(s: &str) -> Result<u32, ParseError> {
+    match s.to_lowercase().as_str() {
+        "january" | "jan" => Ok(1),
+        "february" | "feb" => Ok(2),
+        "march" | "mar" => Ok(3),
+        "april" | "apr" => Ok(4),
+        "may" => Ok(5),
+        "june" | "jun" => Ok(6),
+        "july" | "jul" => Ok(7),
+        "august" | "aug" => Ok(8),
+        "september" | "sep" => Ok(9),
+        "october" | "oct" => Ok(10),
+        "november" | "nov" => Ok(11),
+        "december" | "dec" => Ok(12),
+        _ => Err(ParseError::UnknownMonth(s.to_string())),
+    }
+}
+
 #[derive(Debug, PartialEq)]
 pub enum Modifier {
     Last,
@@ -71,27 +101,318 @@ fn modifier_from(s: &str) -> Result<Modifier, ParseError> {
 
 #[derive(Debug, PartialEq)]
 pub enum Quantifier {
+    Sec,
     Min,
+    Hour,
     Days,
+    Week,
+    Month,
+    Year,
 }
 
 fn quantifier_from(s: &str) -> Result<Quantifier, ParseError> {
     match s {
-        "min" => Ok(Quantifier::Min),
-        "days" => Ok(Quantifier::Days),
+        "min" | "mins" | "minute" | "minutes" => Ok(Quantifier::Min),
+        "s" | "sec" | "secs" | "second" | "seconds" => Ok(Quantifier::Sec),
+        "h" | "hr" | "hrs" | "hour" | "hours" => Ok(Quantifier::Hour),
+        "days" | "day" => Ok(Quantifier::Days),
+        "w" | "wk" | "week" | "weeks" => Ok(Quantifier::Week),
+        "mo" | "month" | "months" => Ok(Quantifier::Month),
+        "y" | "yr" | "year" | "years" => Ok(Quantifier::Year),
         _ => Err(ParseError::UnknownQuantifier(s.to_string())),
     }
 }
 
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Direction {
+    After,
+    Before,
+}
+
+fn direction_from(s: &str) -> Result<Direction, ParseError> {
+    match s {
+        "after" | "from" => Ok(Direction::After),
+        "before" => Ok(Direction::Before),
+        _ => Err(ParseError::UnknownDirection(s.to_string())),
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Freq {
+    Daily,
+    Weekly,
+    Monthly,
+    Yearly,
+}
+
+fn freq_from(s: &str) -> Result<Freq, ParseError> {
+    match s {
+        "day" | "days" => Ok(Freq::Daily),
+        "week" | "weeks" => Ok(Freq::Weekly),
+        "month" | "months" => Ok(Freq::Monthly),
+        "year" | "years" => Ok(Freq::Yearly),
+        _ => Err(ParseError::UnknownFreq(s.to_string())),
+    }
+}
+
+/// The day a [`TimeClue::Range`] is anchored to, e.g. the `monday` in
+/// "monday 9:00-17:30" or the `last friday` in "last friday 9-17".
+#[derive(Debug, PartialEq)]
+pub enum RangeDay {
+    RelativeDay(Modifier, Weekday),
+    SameWeekDay(Weekday),
+    ShortcutDay(ShortcutDay),
+}
+
 #[derive(Debug, PartialEq)]
 pub enum TimeClue {
     Now,
     Time(HMS),
-    Relative(usize, Quantifier),
+    Relative(usize, Quantifier, Direction),
     RelativeDayAt(Modifier, Weekday, Option<HMS>),
     SameWeekDayAt(Weekday, Option<HMS>),
     ShortcutDayAt(ShortcutDay, Option<HMS>),
     ISO(YMD, HMS),
+    /// A month/day (e.g. "dec 25" or "25 dec 2024"), with an optional year.
+    /// When the year is omitted it is deliberately left unresolved, the same
+    /// way `ShortcutDayAt` leaves "today" unresolved, so a resolver holding
+    /// an explicit reference time supplies it rather than the wall clock.
+    MonthDayAt(u32, u32, Option<i32>, HMS),
+    /// A time-of-day span, e.g. "9-17" or "monday 9:00-17:30". If `end` is
+    /// earlier than `start`, the span crosses midnight into the next day;
+    /// callers resolving this against a date should add a day to `end`.
+    /// The optional leading day (e.g. "monday") the span is anchored to.
+    Range(Option<RangeDay>, HMS, HMS),
+    Offset {
+        amount: i64,
+        quantifier: Quantifier,
+        direction: Direction,
+        base: Box<TimeClue>,
+    },
+    Recurring {
+        freq: Freq,
+        interval: u32,
+        byday: Vec<Weekday>,
+        count: Option<usize>,
+        until: Option<NaiveDate>,
+    },
+}
+
+fn last_day_of_month(year: i32, month: u32) -> Option<u32> {
+    let next = if month == 12 {
+        NaiveDate::from_ymd_opt(year.checked_add(1)?, 1, 1)
+    } else {
+        NaiveDate::from_ymd_opt(year, month + 1, 1)
+    }?;
+    Some((next - Duration::days(1)).day())
+}
+
+/// Builds a `Duration` from a day count, returning `None` instead of
+/// panicking when the count falls outside chrono's representable range.
+fn checked_days(days: i64) -> Option<Duration> {
+    days.checked_mul(86_400_000).map(|_| Duration::days(days))
+}
+
+/// Adds `months` (which may be negative) to `dt`, clamping the day-of-month
+/// to the target month's length. Returns `None` rather than panicking when
+/// the resulting date falls outside the range a `NaiveDate` can represent.
+fn add_months(dt: NaiveDateTime, months: i64) -> Option<NaiveDateTime> {
+    let total = (dt.year() as i64 * 12 + dt.month0() as i64).checked_add(months)?;
+    let year = i32::try_from(total.div_euclid(12)).ok()?;
+    let month = total.rem_euclid(12) as u32 + 1;
+    let day = dt.day().min(last_day_of_month(year, month)?);
+    NaiveDate::from_ymd_opt(year, month, day).map(|d| d.and_time(dt.time()))
+}
+
+/// Materialized occurrences of a [`TimeClue::Recurring`], anchored at a starting datetime.
+///
+/// Non-recurring clues produce no occurrences.
+struct Occurrences {
+    freq: Freq,
+    interval: u32,
+    byday: Vec<Weekday>,
+    count: Option<usize>,
+    until: Option<NaiveDate>,
+    anchor: NaiveDateTime,
+    emitted: usize,
+    period: u32,
+    queue: VecDeque<NaiveDateTime>,
+}
+
+impl Occurrences {
+    fn week_start(date: NaiveDate) -> NaiveDate {
+        date - Duration::days(date.weekday().num_days_from_monday() as i64)
+    }
+
+    /// Computes the next week's candidates and pushes them onto `queue`.
+    ///
+    /// Returns `None` (leaving `queue` untouched) if the interval has grown
+    /// so large that the resulting week falls outside chrono's representable
+    /// date range, which `next` treats as the end of iteration.
+    fn fill_week(&mut self) -> Option<()> {
+        let periods = (self.period as i64).saturating_mul(self.interval as i64);
+        let week_days = periods.checked_mul(7)?;
+        let week_start =
+            Self::week_start(self.anchor.date()).checked_add_signed(checked_days(week_days)?)?;
+        let mut days: Vec<NaiveDate> = self
+            .byday
+            .iter()
+            .map(|wd| week_start + Duration::days(wd.num_days_from_monday() as i64))
+            .collect();
+        days.sort();
+        for date in days {
+            let candidate = date.and_time(self.anchor.time());
+            if self.period == 0 && candidate < self.anchor {
+                continue;
+            }
+            self.queue.push_back(candidate);
+        }
+        self.period += 1;
+        Some(())
+    }
+}
+
+impl Iterator for Occurrences {
+    type Item = NaiveDateTime;
+
+    fn next(&mut self) -> Option<NaiveDateTime> {
+        if let Some(count) = self.count {
+            if self.emitted >= count {
+                return None;
+            }
+        }
+
+        let candidate = if self.freq == Freq::Weekly {
+            while self.queue.is_empty() {
+                self.fill_week()?;
+            }
+            self.queue.pop_front()?
+        } else {
+            let periods = (self.period as i64).saturating_mul(self.interval as i64);
+            let candidate = match self.freq {
+                Freq::Daily => self.anchor.checked_add_signed(checked_days(periods)?)?,
+                Freq::Monthly => add_months(self.anchor, periods)?,
+                Freq::Yearly => add_months(self.anchor, periods.saturating_mul(12))?,
+                Freq::Weekly => unreachable!(),
+            };
+            self.period += 1;
+            candidate
+        };
+
+        if let Some(until) = self.until {
+            if candidate.date() > until {
+                return None;
+            }
+        }
+
+        self.emitted += 1;
+        Some(candidate)
+    }
+}
+
+impl TimeClue {
+    /// Materializes concrete occurrences of a `Recurring` clue, starting from `from`.
+    ///
+    /// `from` also acts as the anchor for the first period and supplies the
+    /// time-of-day carried by every occurrence. Clues other than `Recurring`
+    /// yield an empty iterator.
+    pub fn occurrences(&self, from: NaiveDateTime) -> impl Iterator<Item = NaiveDateTime> {
+        match self {
+            TimeClue::Recurring {
+                freq,
+                interval,
+                byday,
+                count,
+                until,
+            } => {
+                let byday = if byday.is_empty() {
+                    vec![from.weekday()]
+                } else {
+                    byday.clone()
+                };
+                Occurrences {
+                    freq: *freq,
+                    interval: *interval,
+                    byday,
+                    count: *count,
+                    until: *until,
+                    anchor: from,
+                    emitted: 0,
+                    period: 0,
+                    queue: VecDeque::new(),
+                }
+            }
+            _ => Occurrences {
+                freq: Freq::Daily,
+                interval: 1,
+                byday: Vec::new(),
+                count: Some(0),
+                until: None,
+                anchor: from,
+                emitted: 0,
+                period: 0,
+                queue: VecDeque::new(),
+            },
+        }
+    }
+}
+
+fn parse_recurring(rules_and_str: &[(Rule, &str)]) -> Result<TimeClue, ParseError> {
+    let mut interval: u32 = 1;
+    let mut freq: Option<Freq> = None;
+    let mut byday: Vec<Weekday> = Vec::new();
+    let mut count: Option<usize> = None;
+    let mut until: Option<NaiveDate> = None;
+
+    let mut i = 0;
+    while i < rules_and_str.len() {
+        let (rule, s) = rules_and_str[i];
+        match rule {
+            Rule::int => {
+                let n: u32 = s.parse()?;
+                if matches!(
+                    rules_and_str.get(i + 1),
+                    Some((Rule::freq_unit, _)) | Some((Rule::weekly_unit, _))
+                ) {
+                    interval = n;
+                } else {
+                    count = Some(n as usize);
+                }
+            }
+            Rule::freq_unit | Rule::weekly_unit => freq = Some(freq_from(s)?),
+            Rule::weekday => byday.push(weekday_from(s)?),
+            Rule::year => {
+                let y: i32 = s.parse()?;
+                let m: u32 = match rules_and_str.get(i + 1) {
+                    Some((Rule::month, m)) => m.parse()?,
+                    _ => return Err(ParseError::UnexpectedNonMatchingPattern),
+                };
+                let d: u32 = match rules_and_str.get(i + 2) {
+                    Some((Rule::day, d)) => d.parse()?,
+                    _ => return Err(ParseError::UnexpectedNonMatchingPattern),
+                };
+                until = Some(
+                    NaiveDate::from_ymd_opt(y, m, d)
+                        .ok_or(ParseError::UnexpectedNonMatchingPattern)?,
+                );
+                i += 2;
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+
+    if interval == 0 {
+        return Err(ParseError::ZeroInterval);
+    }
+
+    Ok(TimeClue::Recurring {
+        freq: freq.unwrap_or(Freq::Weekly),
+        interval,
+        byday,
+        count,
+        until,
+    })
 }
 
 fn parse_time_hms(rules_and_str: &[(Rule, &str)]) -> Result<TimeClue, ParseError> {
@@ -115,23 +436,130 @@ fn parse_time_hms(rules_and_str: &[(Rule, &str)]) -> Result<TimeClue, ParseError
     }
 }
 
-fn parse_time_clue(pairs: &[Pair<Rule>]) -> Result<TimeClue, ParseError> {
-    let rules_and_str: Vec<(Rule, &str)> = pairs
+fn parse_offset(rules_and_str: &[(Rule, &str)]) -> Result<TimeClue, ParseError> {
+    match rules_and_str {
+        [(Rule::int, amount), (Rule::quantifier, q), (Rule::offset_dir, dir), base @ ..] => {
+            let amount: i64 = amount.parse()?;
+            let quantifier = quantifier_from(q)?;
+            let direction = direction_from(dir)?;
+            let base = Box::new(parse_clue(base)?);
+            Ok(TimeClue::Offset {
+                amount,
+                quantifier,
+                direction,
+                base,
+            })
+        }
+        _ => Err(ParseError::UnexpectedNonMatchingPattern),
+    }
+}
+
+fn parse_range_day(rules_and_str: &[(Rule, &str)]) -> Result<RangeDay, ParseError> {
+    match rules_and_str {
+        [(Rule::modifier, m), (Rule::weekday, w)] => {
+            Ok(RangeDay::RelativeDay(modifier_from(m)?, weekday_from(w)?))
+        }
+        [(Rule::weekday, w)] => Ok(RangeDay::SameWeekDay(weekday_from(w)?)),
+        [(Rule::shortcut_day, r)] => Ok(RangeDay::ShortcutDay(shortcut_day_from(r)?)),
+        _ => Err(ParseError::UnexpectedNonMatchingPattern),
+    }
+}
+
+fn parse_range(rules_and_str: &[(Rule, &str)]) -> Result<TimeClue, ParseError> {
+    // An optional leading day clue may precede the range (e.g. "monday
+    // 9:00-17:30"); locate where it ends so we can parse it separately from
+    // the two `time` endpoints that follow it.
+    let mut i = 0;
+    let day = if matches!(rules_and_str.first(), Some((Rule::range_day, _))) {
+        i += 1;
+        let day_start = i;
+        while !matches!(rules_and_str.get(i), Some((Rule::time, _)) | None) {
+            i += 1;
+        }
+        Some(parse_range_day(&rules_and_str[day_start..i])?)
+    } else {
+        None
+    };
+    let first_time = i;
+    let second_time = rules_and_str[first_time + 1..]
         .iter()
-        .map(|pair| (pair.as_rule(), pair.as_str()))
-        .collect();
-    match rules_and_str.as_slice() {
-        [(Rule::time_clue, _), (Rule::now, _), (Rule::EOI, _)] => Ok(TimeClue::Now),
-        [(Rule::time_clue, _), (Rule::time, _), time_hms @ .., (Rule::EOI, _)] => {
-            parse_time_hms(time_hms)
+        .position(|(rule, _)| *rule == Rule::time)
+        .map(|pos| first_time + 1 + pos)
+        .ok_or(ParseError::UnexpectedNonMatchingPattern)?;
+
+    let start = match parse_time_hms(&rules_and_str[first_time + 1..second_time])? {
+        TimeClue::Time(hms) => hms,
+        _ => return Err(ParseError::UnexpectedNonMatchingPattern),
+    };
+    let end = match parse_time_hms(&rules_and_str[second_time + 1..])? {
+        TimeClue::Time(hms) => hms,
+        _ => return Err(ParseError::UnexpectedNonMatchingPattern),
+    };
+
+    Ok(TimeClue::Range(day, start, end))
+}
+
+fn parse_month_name_date(rules_and_str: &[(Rule, &str)]) -> Result<TimeClue, ParseError> {
+    let mut day_val: Option<u32> = None;
+    let mut month_val: Option<u32> = None;
+    let mut year_val: Option<i32> = None;
+    let mut time_hms: &[(Rule, &str)] = &[];
+
+    let mut i = 0;
+    while i < rules_and_str.len() {
+        match rules_and_str[i] {
+            (Rule::day, d) => day_val = Some(d.parse()?),
+            (Rule::month_name, m) => month_val = Some(month_from(m)?),
+            (Rule::year, y) => year_val = Some(y.parse()?),
+            (Rule::time, _) => {
+                time_hms = &rules_and_str[i + 1..];
+                break;
+            }
+            _ => {}
         }
-        [(Rule::time_clue, _), (Rule::relative, _), (Rule::int, s), (Rule::quantifier, q), (Rule::EOI, _)] =>
-        {
-            let n: usize = s.parse()?;
-            let q = quantifier_from(q)?;
-            Ok(TimeClue::Relative(n, q))
+        i += 1;
+    }
+
+    let day = day_val.ok_or(ParseError::UnexpectedNonMatchingPattern)?;
+    let month = month_val.ok_or(ParseError::UnexpectedNonMatchingPattern)?;
+    let hms = if time_hms.is_empty() {
+        (0, 0, 0)
+    } else {
+        match parse_time_hms(time_hms)? {
+            TimeClue::Time(hms) => hms,
+            _ => return Err(ParseError::UnexpectedNonMatchingPattern),
         }
-        [(Rule::time_clue, _), (Rule::day_at, _), (Rule::mday, _), mday @ .., (Rule::EOI, _)] => {
+    };
+
+    // No year was given, e.g. "dec 25" — leave it unresolved for the caller
+    // to supply from its own reference time, rather than the wall clock.
+    Ok(TimeClue::MonthDayAt(month, day, year_val, hms))
+}
+
+fn parse_clue(rules_and_str: &[(Rule, &str)]) -> Result<TimeClue, ParseError> {
+    match rules_and_str {
+        [(Rule::now, _)] => Ok(TimeClue::Now),
+        [(Rule::recurring, _), recurring @ ..] => parse_recurring(recurring),
+        [(Rule::offset, _), offset @ ..] => parse_offset(offset),
+        [(Rule::range, _), range @ ..] => parse_range(range),
+        [(Rule::month_name_date, _), month_name_date @ ..] => {
+            parse_month_name_date(month_name_date)
+        }
+        [(Rule::time, _), time_hms @ ..] => parse_time_hms(time_hms),
+        [(Rule::relative, _), relative @ ..] => match relative {
+            [(Rule::relative_past, _), (Rule::int, s), (Rule::quantifier, q)] => {
+                let n: usize = s.parse()?;
+                let q = quantifier_from(q)?;
+                Ok(TimeClue::Relative(n, q, Direction::Before))
+            }
+            [(Rule::relative_future, _), (Rule::int, s), (Rule::quantifier, q)] => {
+                let n: usize = s.parse()?;
+                let q = quantifier_from(q)?;
+                Ok(TimeClue::Relative(n, q, Direction::After))
+            }
+            _ => Err(ParseError::UnexpectedNonMatchingPattern),
+        },
+        [(Rule::day_at, _), (Rule::mday, _), mday @ ..] => {
             match mday {
                 [(Rule::modifier, m), (Rule::weekday, w), (Rule::time, _), time_hms @ ..] => {
                     let time_maybe = match parse_time_hms(time_hms)? {
@@ -155,6 +583,10 @@ fn parse_time_clue(pairs: &[Pair<Rule>]) -> Result<TimeClue, ParseError> {
                     let w = weekday_from(w)?;
                     Ok(TimeClue::SameWeekDayAt(w, time_maybe))
                 }
+                [(Rule::weekday, w)] => {
+                    let w = weekday_from(w)?;
+                    Ok(TimeClue::SameWeekDayAt(w, None))
+                }
                 [(Rule::shortcut_day, r), (Rule::time, _), time_hms @ ..] => {
                     let time_maybe = match parse_time_hms(time_hms)? {
                         TimeClue::Time(hms) => Some(hms),
@@ -170,7 +602,7 @@ fn parse_time_clue(pairs: &[Pair<Rule>]) -> Result<TimeClue, ParseError> {
                 _ => Err(ParseError::UnexpectedNonMatchingPattern),
             }
         }
-        [(Rule::time_clue, _), (Rule::iso, _), (Rule::year, y), (Rule::month, m), (Rule::day, d), (Rule::time, _), time_hms @ .., (Rule::EOI, _)] => {
+        [(Rule::iso, _), (Rule::year, y), (Rule::month, m), (Rule::day, d), (Rule::time, _), time_hms @ ..] => {
             match parse_time_hms(time_hms)? {
                 TimeClue::Time(hms) => {
                     let y: i32 = y.parse()?;
@@ -185,7 +617,39 @@ fn parse_time_clue(pairs: &[Pair<Rule>]) -> Result<TimeClue, ParseError> {
     }
 }
 
+fn parse_time_clue(pairs: &[Pair<Rule>]) -> Result<TimeClue, ParseError> {
+    let rules_and_str: Vec<(Rule, &str)> = pairs
+        .iter()
+        .map(|pair| (pair.as_rule(), pair.as_str()))
+        .collect();
+    match rules_and_str.as_slice() {
+        [(Rule::time_clue, _), clue @ .., (Rule::EOI, _)] => parse_clue(clue),
+        _ => Err(ParseError::UnexpectedNonMatchingPattern),
+    }
+}
+
+/// `offset` recurses into `clue`, which can itself be another `offset`, so
+/// both pest's own parse and our `parse_offset -> parse_clue` call chain
+/// recurse once per nesting level. Deeply nested input (e.g. thousands of
+/// repeats of "1 s after ...") can blow the stack before a single `TimeClue`
+/// is built, so reject excessive nesting up front rather than parsing it.
+const MAX_OFFSET_DEPTH: usize = 32;
+
+fn check_offset_depth(s: &str) -> Result<(), ParseError> {
+    // Count substrings rather than whitespace-separated words: the grammar's
+    // implicit whitespace is optional, so "1safter1safter..." nests as well.
+    let depth: usize = ["after", "before", "from"]
+        .iter()
+        .map(|w| s.matches(w).count())
+        .sum();
+    if depth > MAX_OFFSET_DEPTH {
+        return Err(ParseError::OffsetTooDeep(MAX_OFFSET_DEPTH));
+    }
+    Ok(())
+}
+
 pub fn parse_time_clue_from_str(s: &str) -> Result<TimeClue, ParseError> {
+    check_offset_depth(s)?;
     let pairs: Pairs<Rule> = TimeParser::parse(Rule::time_clue, s)?;
     let pairs: Vec<Pair<Rule>> = pairs.flatten().collect();
     parse_time_clue(pairs.as_slice())
@@ -193,8 +657,11 @@ pub fn parse_time_clue_from_str(s: &str) -> Result<TimeClue, ParseError> {
 
 #[cfg(test)]
 mod test {
-    use crate::parser::{parse_time_clue_from_str, Modifier, Quantifier, ShortcutDay, TimeClue};
-    use chrono::Weekday;
+    use crate::parser::{
+        parse_time_clue_from_str, Direction, Freq, Modifier, ParseError, Quantifier, RangeDay,
+        ShortcutDay, TimeClue,
+    };
+    use chrono::{NaiveDate, Weekday};
 
     #[test]
     fn test_parse_time_ok() {
@@ -215,23 +682,68 @@ mod test {
     #[test]
     fn test_parse_relative_ok() {
         assert_eq!(
-            TimeClue::Relative(2, Quantifier::Min),
+            TimeClue::Relative(2, Quantifier::Min, Direction::Before),
             parse_time_clue_from_str("2 min ago").unwrap()
         );
         assert_eq!(
-            TimeClue::Relative(2, Quantifier::Min),
+            TimeClue::Relative(2, Quantifier::Min, Direction::Before),
             parse_time_clue_from_str("2min ago").unwrap()
         );
         assert_eq!(
-            TimeClue::Relative(2, Quantifier::Min),
+            TimeClue::Relative(2, Quantifier::Min, Direction::Before),
             parse_time_clue_from_str("2minago").unwrap()
         );
         assert_eq!(
-            TimeClue::Relative(2, Quantifier::Min),
+            TimeClue::Relative(2, Quantifier::Min, Direction::Before),
             parse_time_clue_from_str("2  min   ago").unwrap()
         );
     }
 
+    #[test]
+    fn test_parse_relative_future_ok() {
+        assert_eq!(
+            TimeClue::Relative(3, Quantifier::Hour, Direction::After),
+            parse_time_clue_from_str("in 3 hours").unwrap()
+        );
+        assert_eq!(
+            TimeClue::Relative(10, Quantifier::Sec, Direction::After),
+            parse_time_clue_from_str("in 10s").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse_quantifier_aliases_ok() {
+        assert_eq!(
+            TimeClue::Relative(3, Quantifier::Hour, Direction::Before),
+            parse_time_clue_from_str("3 hrs ago").unwrap()
+        );
+        assert_eq!(
+            TimeClue::Relative(2, Quantifier::Week, Direction::Before),
+            parse_time_clue_from_str("2 wk ago").unwrap()
+        );
+        assert_eq!(
+            TimeClue::Relative(1, Quantifier::Month, Direction::Before),
+            parse_time_clue_from_str("1 mo ago").unwrap()
+        );
+        assert_eq!(
+            TimeClue::Relative(5, Quantifier::Year, Direction::Before),
+            parse_time_clue_from_str("5 yr ago").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse_offset_from_now_ok() {
+        assert_eq!(
+            TimeClue::Offset {
+                amount: 3,
+                quantifier: Quantifier::Hour,
+                direction: Direction::After,
+                base: Box::new(TimeClue::Now),
+            },
+            parse_time_clue_from_str("3 hours from now").unwrap()
+        );
+    }
+
     #[test]
     fn test_parse_shortcut_day_ok() {
         assert_eq!(
@@ -285,4 +797,201 @@ mod test {
     fn test_parse_now_ok() {
         assert_eq!(TimeClue::Now, parse_time_clue_from_str("now").unwrap());
     }
+
+    #[test]
+    fn test_parse_recurring_ok() {
+        assert_eq!(
+            TimeClue::Recurring {
+                freq: Freq::Weekly,
+                interval: 1,
+                byday: vec![Weekday::Mon],
+                count: None,
+                until: None,
+            },
+            parse_time_clue_from_str("every monday").unwrap()
+        );
+        assert_eq!(
+            TimeClue::Recurring {
+                freq: Freq::Weekly,
+                interval: 2,
+                byday: vec![Weekday::Mon, Weekday::Thu],
+                count: None,
+                until: None,
+            },
+            parse_time_clue_from_str("every 2 weeks on monday and thursday").unwrap()
+        );
+        assert_eq!(
+            TimeClue::Recurring {
+                freq: Freq::Daily,
+                interval: 1,
+                byday: vec![],
+                count: None,
+                until: Some(NaiveDate::from_ymd_opt(2024, 12, 31).unwrap()),
+            },
+            parse_time_clue_from_str("every day until 2024-12-31").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse_recurring_zero_interval_err() {
+        assert!(parse_time_clue_from_str("every 0 days").is_err());
+    }
+
+    #[test]
+    fn test_parse_recurring_byday_non_weekly_err() {
+        assert!(parse_time_clue_from_str("every day on monday").is_err());
+        assert!(parse_time_clue_from_str("every month on monday").is_err());
+        assert!(parse_time_clue_from_str("every year on monday").is_err());
+    }
+
+    #[test]
+    fn test_occurrences_weekly_byday() {
+        let clue = parse_time_clue_from_str("every 2 weeks on monday and thursday").unwrap();
+        let anchor = NaiveDate::from_ymd_opt(2024, 1, 1)
+            .unwrap()
+            .and_hms_opt(9, 0, 0)
+            .unwrap();
+        let occurrences: Vec<_> = clue.occurrences(anchor).take(3).collect();
+        assert_eq!(
+            vec![
+                NaiveDate::from_ymd_opt(2024, 1, 1)
+                    .unwrap()
+                    .and_hms_opt(9, 0, 0)
+                    .unwrap(),
+                NaiveDate::from_ymd_opt(2024, 1, 4)
+                    .unwrap()
+                    .and_hms_opt(9, 0, 0)
+                    .unwrap(),
+                NaiveDate::from_ymd_opt(2024, 1, 15)
+                    .unwrap()
+                    .and_hms_opt(9, 0, 0)
+                    .unwrap(),
+            ],
+            occurrences
+        );
+    }
+
+    #[test]
+    fn test_parse_weekday_case_insensitive_and_abbreviated_ok() {
+        assert_eq!(
+            TimeClue::SameWeekDayAt(Weekday::Fri, None),
+            parse_time_clue_from_str("Friday").unwrap()
+        );
+        assert_eq!(
+            TimeClue::SameWeekDayAt(Weekday::Mon, None),
+            parse_time_clue_from_str("Mon").unwrap()
+        );
+        assert_eq!(
+            TimeClue::RelativeDayAt(Modifier::Last, Weekday::Fri, None),
+            parse_time_clue_from_str("last FRI").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse_month_name_date_ok() {
+        assert_eq!(
+            TimeClue::MonthDayAt(12, 25, Some(2024), (9, 0, 0)),
+            parse_time_clue_from_str("25 dec 2024 at 9").unwrap()
+        );
+        assert_eq!(
+            TimeClue::MonthDayAt(12, 25, None, (0, 0, 0)),
+            parse_time_clue_from_str("dec 25").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse_range_ok() {
+        assert_eq!(
+            TimeClue::Range(None, (9, 0, 0), (17, 0, 0)),
+            parse_time_clue_from_str("9-17").unwrap()
+        );
+        assert_eq!(
+            TimeClue::Range(None, (9, 0, 0), (17, 30, 0)),
+            parse_time_clue_from_str("9:00-17:30").unwrap()
+        );
+        assert_eq!(
+            TimeClue::Range(
+                Some(RangeDay::SameWeekDay(Weekday::Mon)),
+                (9, 0, 0),
+                (17, 30, 0)
+            ),
+            parse_time_clue_from_str("monday 9:00-17:30").unwrap()
+        );
+        assert_eq!(
+            TimeClue::Range(
+                Some(RangeDay::RelativeDay(Modifier::Last, Weekday::Fri)),
+                (9, 0, 0),
+                (17, 30, 0)
+            ),
+            parse_time_clue_from_str("last friday 9:00-17:30").unwrap()
+        );
+        assert_eq!(
+            TimeClue::Range(
+                Some(RangeDay::ShortcutDay(ShortcutDay::Today)),
+                (9, 0, 0),
+                (17, 30, 0)
+            ),
+            parse_time_clue_from_str("today 9:00-17:30").unwrap()
+        );
+        assert_eq!(
+            TimeClue::Range(None, (22, 0, 0), (2, 0, 0)),
+            parse_time_clue_from_str("22 to 2").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse_offset_ok() {
+        assert_eq!(
+            TimeClue::Offset {
+                amount: 2,
+                quantifier: Quantifier::Days,
+                direction: Direction::Before,
+                base: Box::new(TimeClue::RelativeDayAt(Modifier::Last, Weekday::Fri, None)),
+            },
+            parse_time_clue_from_str("2 days before last friday").unwrap()
+        );
+        assert_eq!(
+            TimeClue::Offset {
+                amount: 3,
+                quantifier: Quantifier::Days,
+                direction: Direction::After,
+                base: Box::new(TimeClue::ShortcutDayAt(ShortcutDay::Today, None)),
+            },
+            parse_time_clue_from_str("3 days after today").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse_offset_too_deep_err() {
+        let nested = "1 s after ".repeat(1000) + "now";
+        assert!(matches!(
+            parse_time_clue_from_str(&nested),
+            Err(ParseError::OffsetTooDeep(_))
+        ));
+    }
+
+    #[test]
+    fn test_occurrences_until() {
+        let clue = parse_time_clue_from_str("every day until 2024-01-03").unwrap();
+        let anchor = NaiveDate::from_ymd_opt(2024, 1, 1)
+            .unwrap()
+            .and_hms_opt(0, 0, 0)
+            .unwrap();
+        let occurrences: Vec<_> = clue.occurrences(anchor).collect();
+        assert_eq!(3, occurrences.len());
+    }
+
+    #[test]
+    fn test_occurrences_large_interval_does_not_panic() {
+        let clue = parse_time_clue_from_str("every 400000000 years").unwrap();
+        let anchor = NaiveDate::from_ymd_opt(2024, 1, 1)
+            .unwrap()
+            .and_hms_opt(9, 0, 0)
+            .unwrap();
+        // The first occurrence (the anchor itself) is representable; the
+        // next one falls outside chrono's date range, so iteration simply
+        // ends there instead of panicking.
+        let occurrences: Vec<_> = clue.occurrences(anchor).take(2).collect();
+        assert_eq!(vec![anchor], occurrences);
+    }
 }
\ No newline at end of file